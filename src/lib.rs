@@ -6,23 +6,103 @@
 //! - `local@domain`
 //! - `<local@domain>`
 //! - `<>` (null reverse-path)
+//! - `user@[192.0.2.1]` / `user@[IPv6:2001:db8::1]` address literals
+//! - `"quoted local"@domain` quoted local-parts
+//! - `<@a.example,@b.example:local@domain>` deprecated A-d-l source routes
 //!
-//! Display names, comments, and header syntax are rejected.
+//! Which of these relaxations are recognized, and whether brackets or RFC
+//! 5321 length limits are enforced, is controlled by [`ParseOptions`].
+//! [`Addr::parse_envelope`] and [`Addr::parse_path`] are fixed-option
+//! wrappers around [`Addr::parse_with`] for the common cases. Display
+//! names, comments, and other RFC 5322 header syntax are always rejected.
 
 use std::fmt;
+use std::net::IpAddr;
 use std::str::FromStr;
 use thiserror::Error;
 
+mod punycode;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Addr {
     /// The local "john" in "john@doe.com" or "<john@doe.com>"
     pub local: String,
-    /// The domain following the '@' symbol
+    /// The domain following the '@' symbol. For an address literal this is
+    /// the bracket contents (e.g. `"192.0.2.1"` or `"IPv6:2001:db8::1"`),
+    /// without the surrounding `[` `]`.
     pub domain: String,
+    /// Set when `domain` is an RFC 5321 address literal rather than a name,
+    /// holding the parsed IP so callers don't have to re-parse `domain`.
+    pub domain_literal: Option<IpAddr>,
+    /// Set when `local` was written as a quoted-string (`"john doe"@domain`)
+    /// in the source address, so `to_addr_spec` can re-quote it on output.
+    pub quoted_local: bool,
+    /// The deprecated source-route hops (`@a.example,@b.example:`) from an
+    /// RFC 5321 A-d-l, in order. Empty for the common addr-spec case.
+    pub route: Vec<String>,
+}
+
+/// Controls which RFC 5321 relaxations [`Addr::parse_with`] accepts. The
+/// `Default` impl matches the historical, fixed behavior of
+/// [`Addr::parse_envelope`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Recognize `user@[192.0.2.1]` / `user@[IPv6:...]` address literals.
+    pub allow_domain_literals: bool,
+    /// Recognize `"quoted local"@domain` local-parts.
+    pub allow_quoted_local: bool,
+    /// Recognize a leading `@a.example,@b.example:` source route inside
+    /// the brackets (requires brackets regardless of `require_brackets`).
+    pub allow_source_routes: bool,
+    /// Lowercase `domain` (address literals are never lowercased).
+    pub lowercase_domain: bool,
+    /// Reject addresses that aren't wrapped in `<...>`.
+    pub require_brackets: bool,
+    /// Enforce the RFC 5321 octet limits (see [`AddrError::TooLong`]).
+    pub enforce_length_limits: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            allow_domain_literals: true,
+            allow_quoted_local: true,
+            allow_source_routes: false,
+            lowercase_domain: true,
+            require_brackets: false,
+            enforce_length_limits: true,
+        }
+    }
 }
 
 impl Addr {
+    /// Parses an envelope address using the default, historical policy:
+    /// domain literals, quoted local-parts and length limits are all
+    /// recognized, bare (unbracketed) addresses are accepted, and source
+    /// routes are rejected. Equivalent to
+    /// `Addr::parse_with(s, &ParseOptions::default())`.
     pub fn parse_envelope(s: &str) -> Result<Self, AddrError> {
+        Self::parse_with(s, &ParseOptions::default())
+    }
+
+    /// Parses a `forward-path`/`reverse-path` that may carry a deprecated
+    /// RFC 5321 source route (`<@a.example,@b.example:user@domain>`) before
+    /// the real addr-spec. Without a leading `@` inside the brackets this
+    /// behaves exactly like [`Addr::parse_envelope`].
+    pub fn parse_path(s: &str) -> Result<Self, AddrError> {
+        Self::parse_with(
+            s,
+            &ParseOptions {
+                allow_source_routes: true,
+                ..ParseOptions::default()
+            },
+        )
+    }
+
+    /// Parses an envelope address under an explicit [`ParseOptions`]
+    /// policy. `parse_envelope` and `parse_path` are thin wrappers around
+    /// this with fixed option sets.
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, AddrError> {
         // Accept "<a@b>" or "a@b". Reject display-name mailbox forms.
         let mut t = s.trim();
         if t == "<>" {
@@ -30,8 +110,24 @@ impl Addr {
             return Ok(Addr {
                 local: String::new(),
                 domain: String::new(),
+                domain_literal: None,
+                quoted_local: false,
+                route: Vec::new(),
             });
         }
+
+        if opts.allow_source_routes {
+            if let Some(inner) = t.strip_prefix('<').and_then(|t| t.strip_suffix('>')) {
+                if inner.starts_with('@') {
+                    return Self::parse_routed(inner, opts);
+                }
+            }
+        }
+
+        if opts.require_brackets && !(t.starts_with('<') && t.ends_with('>')) {
+            return Err(AddrError::InvalidBrackets);
+        }
+
         let mut is_bracketed = false;
         if let Some(lstrip) = t.strip_prefix("<") {
             is_bracketed = true;
@@ -49,37 +145,189 @@ impl Addr {
                 return Err(AddrError::InvalidBrackets)
             }
         }
-        if t.chars().any(|c| c.is_whitespace()) {
-            // Whitespace indicates separation between a display name and address
-            return Err(AddrError::Whitespace)
-        }
-        if t.contains('<') || t.contains('>') {
-            // Nested or stray angle brackets are invalid after stripping
-            return Err(AddrError::InvalidBrackets);
-        }
         if t.is_empty() {
             return Err(AddrError::Empty);
         }
-        let (local, domain) = t
-            .split_once("@")
-            .ok_or(AddrError::MissingAt)?;
+
+        let (local, domain, quoted_local) = if opts.allow_quoted_local && t.starts_with('"') {
+            let (local, after) = Self::scan_quoted_local(&t[1..])?;
+            let domain = after.strip_prefix('@').ok_or(AddrError::MissingAt)?;
+            (local, domain, true)
+        } else {
+            if t.contains('<') || t.contains('>') {
+                // Nested or stray angle brackets are invalid after stripping
+                return Err(AddrError::InvalidBrackets);
+            }
+            let (local, domain) = t
+                .split_once("@")
+                .ok_or(AddrError::MissingAt)?;
+            if local.chars().any(|c| c.is_whitespace()) {
+                // Whitespace indicates separation between a display name and address
+                return Err(AddrError::Whitespace)
+            }
+            (local, domain, false)
+        };
         if local.is_empty() || domain.is_empty() {
             return Err(AddrError::Empty);
         }
-        if domain.contains("@") {
-            return Err(AddrError::InvalidCharacter)
+        // A bracketed domain is validated as a literal below, where malformed
+        // contents (including stray whitespace, e.g. `[not an ip]`) are
+        // reported as `InvalidDomainLiteral` rather than the generic
+        // whitespace/bracket checks meant for ordinary domain names.
+        let looks_like_literal =
+            opts.allow_domain_literals && domain.starts_with('[') && domain.ends_with(']');
+        if !looks_like_literal {
+            if domain.chars().any(|c| c.is_whitespace()) {
+                return Err(AddrError::Whitespace);
+            }
+            if domain.contains('<') || domain.contains('>') {
+                return Err(AddrError::InvalidBrackets);
+            }
+            if domain.contains("@") {
+                return Err(AddrError::InvalidCharacter)
+            }
         }
-        Ok(Addr {
-            local: local.to_string(),
-            domain: domain.to_ascii_lowercase(),
-        })
+        let literal = if looks_like_literal {
+            domain.strip_prefix('[').and_then(|d| d.strip_suffix(']'))
+        } else {
+            None
+        };
+        let addr = if let Some(literal) = literal {
+            let ip = literal
+                .strip_prefix("IPv6:")
+                .unwrap_or(literal)
+                .parse::<IpAddr>()
+                .map_err(|_| AddrError::InvalidDomainLiteral)?;
+            Addr {
+                local: local.to_string(),
+                domain: literal.to_string(),
+                domain_literal: Some(ip),
+                quoted_local,
+                route: Vec::new(),
+            }
+        } else {
+            Addr {
+                local: local.to_string(),
+                domain: if opts.lowercase_domain {
+                    domain.to_ascii_lowercase()
+                } else {
+                    domain.to_string()
+                },
+                domain_literal: None,
+                quoted_local,
+                route: Vec::new(),
+            }
+        };
+        if opts.enforce_length_limits {
+            addr.check_length_limits()?;
+        }
+        Ok(addr)
+    }
+
+    /// Parses the `@a.example,@b.example:user@domain` contents of a
+    /// source-routed path, given the bracket contents with the outer `<`/`>`
+    /// already stripped.
+    fn parse_routed(inner: &str, opts: &ParseOptions) -> Result<Self, AddrError> {
+        let (hops, addr_spec) = inner.split_once(':').ok_or(AddrError::InvalidSourceRoute)?;
+        if addr_spec.is_empty() {
+            // RFC 5321's A-d-l grammar requires a real mailbox after the
+            // route; an empty one would round-trip to an unparseable
+            // `<@route:@>` via `to_bracketed`.
+            return Err(AddrError::InvalidSourceRoute);
+        }
+        let mut route = Vec::new();
+        for hop in hops.split(',') {
+            let domain = hop.strip_prefix('@').ok_or(AddrError::InvalidSourceRoute)?;
+            if domain.is_empty() || domain.chars().any(|c| c.is_whitespace() || c == '@' || c == ',') {
+                return Err(AddrError::InvalidSourceRoute);
+            }
+            route.push(domain.to_ascii_lowercase());
+        }
+        let mut addr = Self::parse_with(
+            &format!("<{addr_spec}>"),
+            &ParseOptions {
+                allow_source_routes: false,
+                ..*opts
+            },
+        )?;
+        addr.route = route;
+        if opts.enforce_length_limits {
+            addr.check_length_limits()?;
+        }
+        Ok(addr)
+    }
+
+    /// Checks the RFC 5321 octet limits: 64 for the local-part, 63 per
+    /// domain label, 255 for the whole domain, and 256 for the bracketed
+    /// forward-path. The null `<>` address is exempt.
+    fn check_length_limits(&self) -> Result<(), AddrError> {
+        if self.is_null() {
+            return Ok(());
+        }
+        if self.local.len() > 64 {
+            return Err(AddrError::TooLong { part: AddrPart::LocalPart, limit: 64 });
+        }
+        if self.domain_literal.is_none() {
+            for label in self.domain.split('.') {
+                if label.len() > 63 {
+                    return Err(AddrError::TooLong { part: AddrPart::DomainLabel, limit: 63 });
+                }
+            }
+            if self.domain.len() > 255 {
+                return Err(AddrError::TooLong { part: AddrPart::Domain, limit: 255 });
+            }
+        }
+        if self.to_bracketed().len() > 256 {
+            return Err(AddrError::TooLong { part: AddrPart::ForwardPath, limit: 256 });
+        }
+        Ok(())
+    }
+
+    /// Scans a quoted local-part starting just after the opening `"`,
+    /// honoring `\"` and `\\` escapes. Returns the raw (still-escaped)
+    /// contents of the quotes and the remainder of the input following
+    /// the closing `"`. Control characters (including bare CR/LF, which
+    /// could otherwise inject extra SMTP command lines once re-serialized)
+    /// are rejected, escaped or not.
+    fn scan_quoted_local(rest: &str) -> Result<(&str, &str), AddrError> {
+        let mut chars = rest.char_indices();
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    let (_, escaped) = chars.next().ok_or(AddrError::UnterminatedQuote)?;
+                    if escaped.is_control() {
+                        return Err(AddrError::InvalidCharacter);
+                    }
+                }
+                '"' => {
+                    return Ok((&rest[..idx], &rest[idx + 1..]));
+                }
+                c if c.is_control() => {
+                    return Err(AddrError::InvalidCharacter);
+                }
+                _ => {}
+            }
+        }
+        Err(AddrError::UnterminatedQuote)
     }
 
     pub fn to_addr_spec(&self) -> String {
-        let mut s = String::with_capacity(self.local.len() + 1 + self.domain.len());
-        s.push_str(&self.local);
-        s.push('@');
-        s.push_str(&self.domain);
+        let mut s = String::with_capacity(self.local.len() + self.domain.len() + 4);
+        if self.quoted_local {
+            s.push('"');
+            s.push_str(&self.local);
+            s.push('"');
+        } else {
+            s.push_str(&self.local);
+        }
+        if self.domain_literal.is_some() {
+            s.push_str("@[");
+            s.push_str(&self.domain);
+            s.push(']');
+        } else {
+            s.push('@');
+            s.push_str(&self.domain);
+        }
         s
     }
 
@@ -91,6 +339,16 @@ impl Addr {
         let a = self.to_addr_spec();
         let mut s = String::with_capacity(a.len() + 2);
         s.push('<');
+        for (i, hop) in self.route.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+            s.push('@');
+            s.push_str(hop);
+        }
+        if !self.route.is_empty() {
+            s.push(':');
+        }
         s.push_str(&a);
         s.push('>');
         s
@@ -100,6 +358,71 @@ impl Addr {
         Addr {
             local: self.local.clone(),
             domain: domain.into(),
+            domain_literal: None,
+            quoted_local: self.quoted_local,
+            route: self.route.clone(),
+        }
+    }
+
+    /// Returns a copy of this address with `domain` converted to its IDNA
+    /// ASCII-compatible encoding (each non-ASCII label gets an `xn--`
+    /// A-label). Address literals and already-ASCII domains are returned
+    /// unchanged. Fails if a label's Punycode expansion would exceed the
+    /// 63-octet label limit.
+    ///
+    /// This does **not** apply Unicode (NFC) normalization first, unlike
+    /// full IDNA ToASCII. Two domains that are canonically equivalent but
+    /// differ in their precise code points, such as a precomposed `é`
+    /// versus an `e` followed by a combining acute accent, will produce
+    /// different A-labels. Callers that accept envelope addresses from
+    /// untrusted input and need a single canonical A-label per domain
+    /// must normalize `domain` to NFC themselves before calling this.
+    pub fn to_ascii(&self) -> Result<Addr, AddrError> {
+        if self.domain_literal.is_some() || self.domain.is_empty() {
+            return Ok(self.clone());
+        }
+        let mut labels = Vec::new();
+        for label in self.domain.split('.') {
+            if label.is_ascii() {
+                labels.push(label.to_string());
+            } else {
+                let encoded = punycode::encode(label).map_err(|_| AddrError::InvalidIdna)?;
+                let mut a_label = String::with_capacity(4 + encoded.len());
+                a_label.push_str("xn--");
+                a_label.push_str(&encoded);
+                if a_label.len() > 63 {
+                    return Err(AddrError::InvalidIdna);
+                }
+                labels.push(a_label);
+            }
+        }
+        Ok(Addr {
+            domain: labels.join("."),
+            ..self.clone()
+        })
+    }
+
+    /// Returns a copy of this address with every `xn--` label in `domain`
+    /// decoded back to Unicode. Labels that aren't valid Punycode are left
+    /// untouched.
+    ///
+    /// The decoded code points are returned as-is, with no NFC
+    /// normalization applied; see the caveat on [`Addr::to_ascii`].
+    pub fn to_unicode(&self) -> Addr {
+        if self.domain_literal.is_some() || self.domain.is_empty() {
+            return self.clone();
+        }
+        let labels: Vec<String> = self
+            .domain
+            .split('.')
+            .map(|label| match label.strip_prefix("xn--") {
+                Some(rest) => punycode::decode(rest).unwrap_or_else(|_| label.to_string()),
+                None => label.to_string(),
+            })
+            .collect();
+        Addr {
+            domain: labels.join("."),
+            ..self.clone()
         }
     }
 }
@@ -111,7 +434,7 @@ impl fmt::Display for Addr {
         if self.is_null() {
             write!(f, "<>")
         } else {
-            write!(f, "{}@{}", self.local, self.domain)
+            write!(f, "{}", self.to_addr_spec())
         }
     }
 }
@@ -124,7 +447,31 @@ impl FromStr for Addr {
     }
 }
 
-#[derive(Debug, Error)]
+/// The component of an address that a [`AddrError::TooLong`] violation refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddrPart {
+    /// The `local` field
+    LocalPart,
+    /// A single dot-separated domain label
+    DomainLabel,
+    /// The whole `domain` field
+    Domain,
+    /// The bracketed `<local@domain>` forward-path/reverse-path
+    ForwardPath,
+}
+
+impl fmt::Display for AddrPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddrPart::LocalPart => write!(f, "local-part"),
+            AddrPart::DomainLabel => write!(f, "domain label"),
+            AddrPart::Domain => write!(f, "domain"),
+            AddrPart::ForwardPath => write!(f, "forward-path"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
 /// Possible reasons for address parsing failure
 pub enum AddrError {
     #[error("address was empty")]
@@ -142,11 +489,26 @@ pub enum AddrError {
     #[error("address contained whitespace or a display name")]
     /// Address included whitespace in email, which usually means it was a RFC 5322 address
     Whitespace,
+    #[error("address contained a malformed domain literal")]
+    /// Domain was bracketed (`[...]`) but its contents weren't a valid IP address
+    InvalidDomainLiteral,
+    #[error("quoted local-part was missing its closing quote")]
+    /// Local part started with `"` but no matching unescaped closing `"` was found
+    UnterminatedQuote,
+    #[error("domain label could not be converted to/from IDNA ASCII form")]
+    /// A domain label failed Punycode conversion or exceeded the 63-octet label limit
+    InvalidIdna,
+    #[error("address contained a malformed source route")]
+    /// The `@a.example,@b.example:` A-d-l prefix was present but not well-formed
+    InvalidSourceRoute,
+    #[error("{part} exceeded the {limit}-octet RFC 5321 length limit")]
+    /// A component exceeded its RFC 5321 octet limit
+    TooLong { part: AddrPart, limit: usize },
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Addr;
+    use super::{Addr, AddrError, AddrPart, ParseOptions};
 
     #[test]
     fn parses_plain_address_and_normalizes_domain() {
@@ -242,4 +604,264 @@ mod tests {
         assert_eq!(addr.domain, "例え.テスト");
         assert_eq!(addr.to_addr_spec(), "álïcé@例え.テスト");
     }
+
+    #[test]
+    fn parses_ipv4_domain_literal() {
+        let addr = Addr::parse_envelope("user@[192.0.2.1]").expect("address should parse");
+        assert_eq!(addr.local, "user");
+        assert_eq!(addr.domain, "192.0.2.1");
+        assert_eq!(addr.domain_literal, Some("192.0.2.1".parse().unwrap()));
+        assert_eq!(addr.to_addr_spec(), "user@[192.0.2.1]");
+    }
+
+    #[test]
+    fn parses_ipv6_domain_literal() {
+        let addr = Addr::parse_envelope("<user@[IPv6:2001:db8::1]>").expect("address should parse");
+        assert_eq!(addr.domain, "IPv6:2001:db8::1");
+        assert_eq!(addr.domain_literal, Some("2001:db8::1".parse().unwrap()));
+        assert_eq!(addr.to_bracketed(), "<user@[IPv6:2001:db8::1]>");
+    }
+
+    #[test]
+    fn rejects_malformed_domain_literal() {
+        let cases = ["user@[not an ip]", "user@[192.0.2.999]", "user@[IPv6:zzzz]"];
+
+        for case in cases {
+            assert!(Addr::parse_envelope(case).is_err(), "{case:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn rejects_whitespace_inside_domain_literal_as_invalid_literal_not_whitespace() {
+        // Whitespace inside `[...]` is a malformed literal, not a display-name
+        // separator, so it must surface the same error as any other
+        // unparsable literal rather than the generic whitespace check.
+        let err = Addr::parse_envelope("user@[not an ip]").unwrap_err();
+        assert_eq!(err, AddrError::InvalidDomainLiteral);
+    }
+
+    #[test]
+    fn parses_quoted_local_part_with_whitespace() {
+        let addr = Addr::parse_envelope("\"John Doe\"@example.com").expect("address should parse");
+        assert!(addr.quoted_local);
+        assert_eq!(addr.local, "John Doe");
+        assert_eq!(addr.domain, "example.com");
+        assert_eq!(addr.to_addr_spec(), "\"John Doe\"@example.com");
+    }
+
+    #[test]
+    fn parses_quoted_local_part_with_escaped_at_and_quote() {
+        let addr = Addr::parse_envelope("<\"a\\@b\\\"c\"@example.com>").expect("address should parse");
+        assert!(addr.quoted_local);
+        assert_eq!(addr.local, "a\\@b\\\"c");
+        assert_eq!(addr.to_bracketed(), "<\"a\\@b\\\"c\"@example.com>");
+    }
+
+    #[test]
+    fn rejects_unterminated_quote() {
+        let cases = ["\"unterminated@example.com", "\"escaped end\\\"@example.com"];
+
+        for case in cases {
+            assert!(Addr::parse_envelope(case).is_err(), "{case:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn rejects_quoted_local_not_followed_by_at() {
+        assert!(Addr::parse_envelope("\"john\" @example.com").is_err());
+    }
+
+    #[test]
+    fn rejects_quoted_local_with_malformed_domain() {
+        let cases = [
+            "\"x\"@exa mple.com",
+            "\"x\"@evil.com\nDATA",
+            "\"x\"@ev<il>.com",
+        ];
+
+        for case in cases {
+            assert!(Addr::parse_envelope(case).is_err(), "{case:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn rejects_quoted_local_with_control_characters() {
+        let cases = [
+            "\"x\r\nRCPT TO:<victim@evil>\"@domain.com",
+            "\"x\\\r\nRCPT TO:<victim@evil>\"@domain.com",
+            "\"x\0y\"@domain.com",
+        ];
+
+        for case in cases {
+            assert!(Addr::parse_envelope(case).is_err(), "{case:?} should be rejected");
+        }
+        assert!(Addr::parse_path(
+            "<@a.example:\"x\r\nRCPT TO:<victim@evil>\"@domain.com>"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn converts_unicode_domain_to_ascii_and_back() {
+        let addr = Addr::parse_envelope("user@例え.テスト").expect("address should parse");
+        let ascii = addr.to_ascii().expect("domain should convert to ASCII");
+        assert_eq!(ascii.domain, "xn--r8jz45g.xn--zckzah");
+
+        let unicode = ascii.to_unicode();
+        assert_eq!(unicode.domain, "例え.テスト");
+    }
+
+    #[test]
+    fn to_ascii_leaves_ascii_domains_unchanged() {
+        let addr = Addr::parse_envelope("user@example.com").expect("address should parse");
+        assert_eq!(addr.to_ascii().unwrap().domain, "example.com");
+    }
+
+    #[test]
+    fn to_ascii_leaves_domain_literals_unchanged() {
+        let addr = Addr::parse_envelope("user@[192.0.2.1]").expect("address should parse");
+        assert_eq!(addr.to_ascii().unwrap().domain, "192.0.2.1");
+    }
+
+    #[test]
+    fn to_ascii_does_not_normalize_canonically_equivalent_domains() {
+        // "café" as a precomposed é vs. "e" + combining acute (U+0301):
+        // same rendered string, different A-labels, per the caveat documented
+        // on `Addr::to_ascii`. Callers needing a single canonical A-label
+        // must normalize `domain` to NFC themselves first.
+        let precomposed = Addr::parse_envelope("user@café.com").unwrap();
+        let decomposed = Addr::parse_envelope("user@cafe\u{0301}.com").unwrap();
+        assert_ne!(
+            precomposed.to_ascii().unwrap().domain,
+            decomposed.to_ascii().unwrap().domain
+        );
+    }
+
+    #[test]
+    fn parses_source_routed_reverse_path() {
+        let addr = Addr::parse_path("<@a.example,@b.example:user@domain.com>")
+            .expect("source-routed path should parse");
+        assert_eq!(addr.route, vec!["a.example", "b.example"]);
+        assert_eq!(addr.local, "user");
+        assert_eq!(addr.domain, "domain.com");
+        assert_eq!(
+            addr.to_bracketed(),
+            "<@a.example,@b.example:user@domain.com>"
+        );
+    }
+
+    #[test]
+    fn parse_path_without_route_matches_parse_envelope() {
+        let addr = Addr::parse_path("<user@domain.com>").expect("address should parse");
+        assert!(addr.route.is_empty());
+        assert_eq!(addr.to_bracketed(), "<user@domain.com>");
+    }
+
+    #[test]
+    fn rejects_malformed_source_routes() {
+        let cases = [
+            "<@a.example:user@domain.com", // missing closing bracket, falls through to envelope parse
+            "<@a.example,b.example:user@domain.com>", // hop missing leading '@'
+            "<@a.example user@domain.com>", // missing ':'
+            "<@a.example:>", // route with no mailbox after it
+        ];
+
+        for case in cases {
+            assert!(Addr::parse_path(case).is_err(), "{case:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn rejects_oversized_local_part() {
+        let local = "a".repeat(65);
+        let err = Addr::parse_envelope(&format!("{local}@example.com")).unwrap_err();
+        assert!(matches!(
+            err,
+            AddrError::TooLong { part: AddrPart::LocalPart, limit: 64 }
+        ));
+    }
+
+    #[test]
+    fn rejects_oversized_domain_label() {
+        let label = "a".repeat(64);
+        let err = Addr::parse_envelope(&format!("user@{label}.com")).unwrap_err();
+        assert!(matches!(
+            err,
+            AddrError::TooLong { part: AddrPart::DomainLabel, limit: 63 }
+        ));
+    }
+
+    #[test]
+    fn rejects_oversized_domain() {
+        let domain = vec!["a".repeat(60); 5].join(".");
+        let err = Addr::parse_envelope(&format!("user@{domain}")).unwrap_err();
+        assert!(matches!(
+            err,
+            AddrError::TooLong { part: AddrPart::Domain, .. }
+        ));
+    }
+
+    #[test]
+    fn allows_null_address_regardless_of_length() {
+        assert!(Addr::parse_envelope("<>").is_ok());
+    }
+
+    #[test]
+    fn parse_with_default_matches_parse_envelope() {
+        let via_parse_with = Addr::parse_with("user@example.com", &ParseOptions::default())
+            .expect("address should parse");
+        let via_parse_envelope =
+            Addr::parse_envelope("user@example.com").expect("address should parse");
+        assert_eq!(via_parse_with, via_parse_envelope);
+    }
+
+    #[test]
+    fn strict_options_require_brackets() {
+        let opts = ParseOptions {
+            require_brackets: true,
+            ..ParseOptions::default()
+        };
+        assert!(Addr::parse_with("user@example.com", &opts).is_err());
+        assert!(Addr::parse_with("<user@example.com>", &opts).is_ok());
+    }
+
+    #[test]
+    fn disabling_domain_literals_treats_brackets_as_plain_domain_text() {
+        let opts = ParseOptions {
+            allow_domain_literals: false,
+            ..ParseOptions::default()
+        };
+        let addr = Addr::parse_with("user@[192.0.2.1]", &opts).expect("address should parse");
+        assert!(addr.domain_literal.is_none());
+        assert_eq!(addr.domain, "[192.0.2.1]");
+    }
+
+    #[test]
+    fn disabling_quoted_local_rejects_quote_as_special() {
+        let opts = ParseOptions {
+            allow_quoted_local: false,
+            ..ParseOptions::default()
+        };
+        assert!(Addr::parse_with("\"John Doe\"@example.com", &opts).is_err());
+    }
+
+    #[test]
+    fn disabling_length_limits_allows_oversized_local_part() {
+        let opts = ParseOptions {
+            enforce_length_limits: false,
+            ..ParseOptions::default()
+        };
+        let local = "a".repeat(65);
+        assert!(Addr::parse_with(&format!("{local}@example.com"), &opts).is_ok());
+    }
+
+    #[test]
+    fn disabling_lowercase_domain_preserves_domain_case() {
+        let opts = ParseOptions {
+            lowercase_domain: false,
+            ..ParseOptions::default()
+        };
+        let addr = Addr::parse_with("user@Example.COM", &opts).expect("address should parse");
+        assert_eq!(addr.domain, "Example.COM");
+    }
 }
\ No newline at end of file
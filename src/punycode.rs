@@ -0,0 +1,198 @@
+//! A small RFC 3492 Punycode encoder/decoder.
+//!
+//! This only implements the Bootstring parameters fixed by RFC 3490/3492 for
+//! IDNA (base 36, digits `a-z0-9`, `xn--` is handled by the caller). It does
+//! not perform Unicode normalization; callers are expected to pass
+//! already-normalized labels.
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PunycodeError {
+    /// An internal delta/index computation overflowed `u32`.
+    Overflow,
+    /// The input was not well-formed Punycode.
+    InvalidInput,
+}
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (((BASE - TMIN + 1) * delta) / (delta + SKEW))
+}
+
+fn encode_digit(d: u32) -> u8 {
+    if d < 26 {
+        b'a' + d as u8
+    } else {
+        b'0' + (d - 26) as u8
+    }
+}
+
+fn decode_digit(c: u8) -> Option<u32> {
+    match c {
+        b'0'..=b'9' => Some(c as u32 - b'0' as u32 + 26),
+        b'a'..=b'z' => Some(c as u32 - b'a' as u32),
+        b'A'..=b'Z' => Some(c as u32 - b'A' as u32),
+        _ => None,
+    }
+}
+
+/// Encodes a single label's code points into the Punycode string that goes
+/// after the `xn--` prefix. The caller is responsible for prepending it.
+pub fn encode(input: &str) -> Result<String, PunycodeError> {
+    let input_chars: Vec<char> = input.chars().collect();
+    let mut output = String::new();
+    for &c in &input_chars {
+        if c.is_ascii() {
+            output.push(c);
+        }
+    }
+    let b = output.len();
+    let mut h = b;
+    if b > 0 {
+        output.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+
+    while h < input_chars.len() {
+        let m = input_chars
+            .iter()
+            .map(|&c| c as u32)
+            .filter(|&c| c >= n)
+            .min()
+            .ok_or(PunycodeError::InvalidInput)?;
+        delta = delta
+            .checked_add((m - n).checked_mul(h as u32 + 1).ok_or(PunycodeError::Overflow)?)
+            .ok_or(PunycodeError::Overflow)?;
+        n = m;
+        for &c in &input_chars {
+            let c = c as u32;
+            if c < n {
+                delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = if k <= bias {
+                        TMIN
+                    } else if k >= bias + TMAX {
+                        TMAX
+                    } else {
+                        k - bias
+                    };
+                    if q < t {
+                        break;
+                    }
+                    let digit = t + (q - t) % (BASE - t);
+                    output.push(encode_digit(digit) as char);
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q) as char);
+                bias = adapt(delta, h as u32 + 1, h == b);
+                delta = 0;
+                h += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Ok(output)
+}
+
+/// Decodes a Punycode string (the part after `xn--`) back into its
+/// original code points.
+pub fn decode(input: &str) -> Result<String, PunycodeError> {
+    let (basic, rest) = match input.rfind('-') {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => ("", input),
+    };
+    let mut output: Vec<char> = Vec::new();
+    for c in basic.chars() {
+        if !c.is_ascii() {
+            return Err(PunycodeError::InvalidInput);
+        }
+        output.push(c);
+    }
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut bytes = rest.bytes();
+
+    while let Some(first) = bytes.next() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        let mut digit = decode_digit(first).ok_or(PunycodeError::InvalidInput)?;
+        loop {
+            i = i
+                .checked_add(digit.checked_mul(w).ok_or(PunycodeError::Overflow)?)
+                .ok_or(PunycodeError::Overflow)?;
+            let t = if k <= bias {
+                TMIN
+            } else if k >= bias + TMAX {
+                TMAX
+            } else {
+                k - bias
+            };
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t).ok_or(PunycodeError::Overflow)?;
+            k += BASE;
+            digit = decode_digit(bytes.next().ok_or(PunycodeError::InvalidInput)?)
+                .ok_or(PunycodeError::InvalidInput)?;
+        }
+        let out_len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, out_len, old_i == 0);
+        n = n.checked_add(i / out_len).ok_or(PunycodeError::Overflow)?;
+        i %= out_len;
+        let c = char::from_u32(n).ok_or(PunycodeError::InvalidInput)?;
+        output.insert(i as usize, c);
+        i += 1;
+    }
+    Ok(output.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+
+    #[test]
+    fn round_trips_ascii() {
+        let encoded = encode("example").unwrap();
+        assert_eq!(encoded, "example-");
+        assert_eq!(decode(&encoded).unwrap(), "example");
+    }
+
+    #[test]
+    fn round_trips_unicode_label() {
+        let label = "例え";
+        let encoded = encode(label).unwrap();
+        assert_eq!(decode(&encoded).unwrap(), label);
+    }
+
+    #[test]
+    fn matches_known_punycode_vector() {
+        // "ドメイン名例" -> xn--eckwd4c7cu47r2wf (well-known IDNA test vector)
+        assert_eq!(encode("ドメイン名例").unwrap(), "eckwd4c7cu47r2wf");
+        assert_eq!(decode("eckwd4c7cu47r2wf").unwrap(), "ドメイン名例");
+    }
+}